@@ -1,13 +1,73 @@
 //! Defines the `Reload` trait.
 
-use std::sync::Arc;
-use std::time::Instant;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hasher;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::channel;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use amethyst_core::ECSBundle;
+use fnv::FnvHasher;
+use notify::{DebouncedEvent, RecommendedWatcher, RecursiveMode, Watcher};
+use shrev::EventChannel;
 use specs::{DispatcherBuilder, FetchMut, System, World};
 
 use {Asset, BoxedErr, Format, FormatValue, Loader, Source};
 
+/// Event emitted whenever a `Reload` successfully produces a new version of an
+/// asset, so gameplay systems can invalidate derived state (GPU buffers, baked
+/// navmeshes, ...) for just the assets that actually changed.
+#[derive(Clone, Debug)]
+pub struct AssetReloaded {
+    /// The reloaded asset's name, as given by `Reload::name`.
+    pub name: String,
+    /// The reloaded asset's format, as given by `Reload::format`.
+    pub format: &'static str,
+    /// Monotonically increasing per-asset counter, incremented on every
+    /// successful reload so consumers can order reloads without comparing data.
+    pub reload_id: u64,
+}
+
+/// A reload event channel paired with the atomic counter reload objects bump on
+/// every successful reload, shared between the reload objects produced across
+/// successive reloads of the same asset.
+pub type ReloadEvents = (Arc<Mutex<EventChannel<AssetReloaded>>>, Arc<AtomicU64>);
+
+fn emit_reload_event(events: &Option<ReloadEvents>, name: String, format: &'static str) {
+    if let Some((ref channel, ref counter)) = *events {
+        let reload_id = counter.fetch_add(1, Ordering::Relaxed) + 1;
+
+        channel.lock().unwrap().single_write(AssetReloaded {
+            name,
+            format,
+            reload_id,
+        });
+    }
+}
+
+/// The default quiet period a changed path must sit untouched for before a
+/// `watched` `HotReloadStrategy` will consider it settled (see `with_delay`).
+pub const DEFAULT_DEBOUNCE_DELAY_MS: u64 = 200;
+
+/// How long a changed path may sit in a `watched` strategy's map without being
+/// consulted by a `Reload` object before `HotReloadSystem` prunes it. Bounds the
+/// map's size for paths that never back a loaded asset (editor swap files,
+/// renamed/deleted assets, directories watched non-recursively for no reason
+/// anymore), which would otherwise never be removed.
+pub const DEFAULT_STALE_PATH_TTL_SECS: u64 = 60;
+
+/// A map of asset paths that have changed on disk to the `Instant` they were last
+/// seen changing, shared between the background filesystem watcher thread and the
+/// reload objects that consult it. Tracking a per-path timestamp (rather than a
+/// plain set) lets each path debounce independently of the others.
+pub type WatchedPaths = Arc<Mutex<HashMap<String, Instant>>>;
+
+/// A set of asset names that should be force-reloaded regardless of their
+/// modification time or watch status, populated by `HotReloadStrategy::force_reload`.
+pub type ForcedPaths = Arc<Mutex<HashSet<String>>>;
+
 /// This bundle activates hot reload for the `Loader`,
 /// adds a `HotReloadStrategy` and the `HotReloadSystem`.
 ///
@@ -32,6 +92,7 @@ impl<'a, 'b> ECSBundle<'a, 'b> for HotReloadBundle {
     ) -> Result<DispatcherBuilder<'a, 'b>, BoxedErr> {
         world.write_resource::<Loader>().set_hot_reload(true);
         world.add_resource(self.strategy);
+        world.add_resource(EventChannel::<AssetReloaded>::new());
 
         Ok(dispatcher.add(HotReloadSystem, "hot_reload", &[]))
     }
@@ -56,6 +117,7 @@ impl<'a, 'b> ECSBundle<'a, 'b> for HotReloadBundle {
 /// ```
 pub struct HotReloadStrategy {
     inner: HotReloadStrategyInner,
+    forced: ForcedPaths,
 }
 
 impl HotReloadStrategy {
@@ -67,6 +129,7 @@ impl HotReloadStrategy {
                 last: Instant::now(),
                 do_reload: false,
             },
+            forced: ForcedPaths::default(),
         }
     }
 
@@ -74,6 +137,7 @@ impl HotReloadStrategy {
     pub fn when_triggered() -> Self {
         HotReloadStrategy {
             inner: HotReloadStrategyInner::Trigger { triggered: false },
+            forced: ForcedPaths::default(),
         }
     }
 
@@ -81,6 +145,91 @@ impl HotReloadStrategy {
     pub fn never() -> Self {
         HotReloadStrategy {
             inner: HotReloadStrategyInner::Never,
+            forced: ForcedPaths::default(),
+        }
+    }
+
+    /// Watches loaded `Source` directories with an OS filesystem watcher instead of
+    /// polling `modified()` on every asset every frame. Directories are registered
+    /// lazily as assets are loaded (see `watch_dir`); changed paths are pushed into
+    /// a shared map that `Reload` implementations (e.g. `SingleFile`) consult
+    /// directly, so idle assets cost nothing.
+    ///
+    /// Changes are debounced by `DEFAULT_DEBOUNCE_DELAY_MS` before they're reported
+    /// as settled, so editors that write a file in several chunks don't trigger a
+    /// reload on the first, half-written chunk. Use `with_delay` to override it.
+    ///
+    /// Falls back to `every(1)` polling if the OS watcher can't be started (e.g.
+    /// inotify instances exhausted, common in containers/CI), per the "keep the
+    /// polling strategy as a fallback" requirement for `Source`s that can't be
+    /// watched.
+    pub fn watched() -> Self {
+        Self::try_watched().unwrap_or_else(|err| {
+            error!(
+                "Failed to start filesystem watcher, falling back to polling: {}",
+                err
+            );
+
+            HotReloadStrategy::every(1)
+        })
+    }
+
+    fn try_watched() -> Result<Self, ::notify::Error> {
+        let changed = WatchedPaths::default();
+        let watched = changed.clone();
+
+        let (tx, rx) = channel();
+        let watcher = RecommendedWatcher::new(tx, Duration::from_millis(0))?;
+
+        ::std::thread::spawn(move || {
+            while let Ok(event) = rx.recv() {
+                let path = match event {
+                    DebouncedEvent::Create(path)
+                    | DebouncedEvent::Write(path)
+                    | DebouncedEvent::Rename(_, path) => Some(path),
+                    _ => None,
+                };
+
+                if let Some(path) = path {
+                    if let Some(path) = path.to_str() {
+                        watched
+                            .lock()
+                            .unwrap()
+                            .insert(path.to_string(), Instant::now());
+                    }
+                }
+            }
+        });
+
+        Ok(HotReloadStrategy {
+            inner: HotReloadStrategyInner::Watched {
+                changed,
+                watcher,
+                delay: Duration::from_millis(DEFAULT_DEBOUNCE_DELAY_MS),
+            },
+            forced: ForcedPaths::default(),
+        })
+    }
+
+    /// Overrides the quiet-period delay a `watched` strategy waits for after the
+    /// last observed change to a path before treating it as settled.
+    /// Does nothing if the strategy wasn't created with `watched`.
+    pub fn with_delay(mut self, new_delay: Duration) -> Self {
+        if let HotReloadStrategyInner::Watched { ref mut delay, .. } = self.inner {
+            *delay = new_delay;
+        }
+
+        self
+    }
+
+    /// Registers a directory to be watched by a `watched` strategy.
+    /// Does nothing if the strategy wasn't created with `watched`.
+    pub fn watch_dir(&mut self, dir: &Path) {
+        if let HotReloadStrategyInner::Watched {
+            ref mut watcher, ..
+        } = self.inner
+        {
+            let _ = watcher.watch(dir, RecursiveMode::NonRecursive);
         }
     }
 
@@ -92,6 +241,33 @@ impl HotReloadStrategy {
         }
     }
 
+    /// Forces the asset named `name` to reload on the next check, regardless of
+    /// whether its `Reload` object can otherwise detect a change. Works with any
+    /// strategy, including ones backed by a `Source` that can't report modification
+    /// times at all.
+    ///
+    /// Only `SingleFile` reload objects set up with `with_forced_reload` consult the
+    /// requested names; `HashedFile` and `MultiFile` ignore them.
+    pub fn force_reload(&self, name: &str) {
+        self.forced.lock().unwrap().insert(name.to_string());
+    }
+
+    /// Crate-internal accessor for the set of names requested via `force_reload`.
+    pub(crate) fn forced_paths(&self) -> ForcedPaths {
+        self.forced.clone()
+    }
+
+    /// Crate-internal accessor for the changed-paths map and debounce delay of a
+    /// `watched` strategy's filesystem watcher. Returns `None` for any other strategy.
+    pub(crate) fn watched_paths(&self) -> Option<(WatchedPaths, Duration)> {
+        match self.inner {
+            HotReloadStrategyInner::Watched {
+                ref changed, delay, ..
+            } => Some((changed.clone(), delay)),
+            _ => None,
+        }
+    }
+
     /// Crate-internal method to check if reload is necessary.
     /// `reload_counter` is a per-storage value which is only used
     /// for and by this method.
@@ -99,11 +275,35 @@ impl HotReloadStrategy {
         match self.inner {
             HotReloadStrategyInner::Every { do_reload, .. } => do_reload,
             HotReloadStrategyInner::Trigger { triggered } => triggered,
+            HotReloadStrategyInner::Watched {
+                ref changed, delay, ..
+            } => any_settled(&changed.lock().unwrap(), delay),
             HotReloadStrategyInner::Never => false,
         }
     }
 }
 
+/// Whether any path in `changed` has sat untouched for at least `delay`, i.e. has
+/// settled and is ready to be reported as changed. Doesn't mutate `changed` — call
+/// sites that consume a single path's settled state should use `take_settled`.
+fn any_settled(changed: &HashMap<String, Instant>, delay: Duration) -> bool {
+    changed.values().any(|changed_at| changed_at.elapsed() >= delay)
+}
+
+/// If `path` has settled in `changed` (sat untouched for at least `delay`), removes
+/// it and returns `true`. Leaves `changed` untouched and returns `false` otherwise.
+fn take_settled(changed: &mut HashMap<String, Instant>, path: &str, delay: Duration) -> bool {
+    let settled = changed
+        .get(path)
+        .map_or(false, |changed_at| changed_at.elapsed() >= delay);
+
+    if settled {
+        changed.remove(path);
+    }
+
+    settled
+}
+
 impl Default for HotReloadStrategy {
     fn default() -> Self {
         HotReloadStrategy::every(1)
@@ -117,6 +317,11 @@ enum HotReloadStrategyInner {
         do_reload: bool,
     },
     Trigger { triggered: bool },
+    Watched {
+        changed: WatchedPaths,
+        watcher: RecommendedWatcher,
+        delay: Duration,
+    },
     Never,
 }
 
@@ -142,6 +347,13 @@ impl<'a> System<'a> for HotReloadSystem {
             } else {
                 *do_reload = false
             },
+            HotReloadStrategyInner::Watched { ref changed, .. } => {
+                let ttl = Duration::from_secs(DEFAULT_STALE_PATH_TTL_SECS);
+                changed
+                    .lock()
+                    .unwrap()
+                    .retain(|_, changed_at| changed_at.elapsed() < ttl);
+            }
             HotReloadStrategyInner::Never => {}
         }
     }
@@ -179,6 +391,27 @@ impl<A: Asset> Clone for Box<Reload<A>> {
     }
 }
 
+/// Lets a boxed `Reload` trait object be used as the `R` of another `Reload`
+/// wrapper (e.g. `Versioned`), which needs `R: Reload<A>` to re-wrap whatever
+/// reload object its inner value produces without knowing its concrete type.
+impl<A: Asset> Reload<A> for Box<Reload<A>> {
+    fn needs_reload(&self) -> bool {
+        (**self).needs_reload()
+    }
+
+    fn reload(self: Box<Self>) -> Result<FormatValue<A>, BoxedErr> {
+        (*self).reload()
+    }
+
+    fn name(&self) -> String {
+        (**self).name()
+    }
+
+    fn format(&self) -> &'static str {
+        (**self).format()
+    }
+}
+
 /// An implementation of `Reload` which just stores the modification time
 /// and the path of the file.
 pub struct SingleFile<A: Asset, F: Format<A>> {
@@ -187,10 +420,14 @@ pub struct SingleFile<A: Asset, F: Format<A>> {
     options: F::Options,
     path: String,
     source: Arc<Source>,
+    watch: Option<(WatchedPaths, Duration)>,
+    forced: Option<ForcedPaths>,
+    events: Option<ReloadEvents>,
 }
 
 impl<A: Asset, F: Format<A>> SingleFile<A, F> {
-    /// Creates a new `SingleFile` reload object.
+    /// Creates a new `SingleFile` reload object, polling `Source::modified` to
+    /// detect changes.
     pub fn new(
         format: F,
         modified: u64,
@@ -204,8 +441,50 @@ impl<A: Asset, F: Format<A>> SingleFile<A, F> {
             options,
             path,
             source,
+            watch: None,
+            forced: None,
+            events: None,
         }
     }
+
+    /// Creates a new `SingleFile` reload object backed by a `HotReloadStrategy::watched`
+    /// strategy, consulting its shared changed-paths map instead of polling `Source::modified`.
+    /// `delay` is the strategy's debounce quiet period, typically obtained alongside
+    /// `watch` from `HotReloadStrategy::watched_paths`.
+    pub fn new_watched(
+        format: F,
+        options: F::Options,
+        path: String,
+        source: Arc<Source>,
+        watch: WatchedPaths,
+        delay: Duration,
+    ) -> Self {
+        SingleFile {
+            format,
+            modified: 0,
+            options,
+            path,
+            source,
+            watch: Some((watch, delay)),
+            forced: None,
+            events: None,
+        }
+    }
+
+    /// Makes this reload object consult `forced`, a `HotReloadStrategy::forced_paths` set,
+    /// so `HotReloadStrategy::force_reload` can trigger a reload regardless of mtime or
+    /// watch status.
+    pub fn with_forced_reload(mut self, forced: ForcedPaths) -> Self {
+        self.forced = Some(forced);
+        self
+    }
+
+    /// Makes this reload object emit an `AssetReloaded` event on `events` whenever it
+    /// successfully reloads, bumping the shared per-asset reload counter.
+    pub fn with_reload_events(mut self, events: ReloadEvents) -> Self {
+        self.events = Some(events);
+        self
+    }
 }
 
 impl<A, F> Clone for SingleFile<A, F>
@@ -221,6 +500,9 @@ where
             options: self.options.clone(),
             path: self.path.clone(),
             source: self.source.clone(),
+            watch: self.watch.clone(),
+            forced: self.forced.clone(),
+            events: self.events.clone(),
         }
     }
 }
@@ -232,6 +514,16 @@ where
     <F as Format<A>>::Options: Clone + Sync,
 {
     fn needs_reload(&self) -> bool {
+        if let Some(ref forced) = self.forced {
+            if forced.lock().unwrap().remove(&self.path) {
+                return true;
+            }
+        }
+
+        if let Some((ref watch, delay)) = self.watch {
+            return take_settled(&mut watch.lock().unwrap(), &self.path, delay);
+        }
+
         self.modified != 0 && (self.source.modified(&self.path).unwrap_or(0) > self.modified)
     }
 
@@ -242,10 +534,37 @@ where
             path,
             source,
             options,
+            watch,
+            forced,
+            events,
             ..
         } = this;
 
-        format.import(path, source, options, true)
+        let name = path.clone();
+        let result = format
+            .import(path.clone(), source.clone(), options.clone(), true)
+            .map(|mut value| {
+                let modified = source.modified(&path).unwrap_or(0);
+
+                value.reload = Some(Box::new(SingleFile {
+                    format,
+                    modified,
+                    options,
+                    path,
+                    source,
+                    watch,
+                    forced,
+                    events: events.clone(),
+                }));
+
+                value
+            });
+
+        if result.is_ok() {
+            emit_reload_event(&events, name, F::NAME);
+        }
+
+        result
     }
 
     fn name(&self) -> String {
@@ -256,3 +575,563 @@ where
         F::NAME
     }
 }
+
+/// A shared history of recently successfully loaded versions of one asset, kept by
+/// `Versioned` across the reload objects produced on each successful reload.
+type VersionHistory<A> = Arc<Mutex<VecDeque<FormatValue<A>>>>;
+
+/// Wraps another `Reload` object, keeping the last few successfully loaded versions of
+/// the asset around. If a reload fails (e.g. a transient syntax error introduced by an
+/// in-progress edit), the error is logged and the last good version is served instead
+/// of propagating the failure. `rollback` additionally allows stepping back to an
+/// earlier good version on demand.
+pub struct Versioned<A: Asset, R> {
+    inner: R,
+    history: VersionHistory<A>,
+    max_versions: usize,
+}
+
+impl<A, R> Versioned<A, R>
+where
+    A: Asset,
+    R: Reload<A>,
+{
+    /// Wraps `inner`, keeping up to `max_versions` recently loaded good versions.
+    /// `initial` is the asset's already-loaded value, seeded into the history so a
+    /// reload that fails before any successful reload has happened still has a last
+    /// good version to fall back to.
+    pub fn new(inner: R, initial: FormatValue<A>, max_versions: usize) -> Self {
+        let mut history = VecDeque::new();
+        history.push_front(initial);
+
+        Versioned {
+            inner,
+            history: Arc::new(Mutex::new(history)),
+            max_versions,
+        }
+    }
+
+    /// Steps back to the previous good version, if one was kept. Returns the
+    /// version rolled back to, or `None` if there's nothing earlier to roll back to.
+    pub fn rollback(&self) -> Option<FormatValue<A>>
+    where
+        FormatValue<A>: Clone,
+    {
+        rollback_in(&mut self.history.lock().unwrap())
+    }
+}
+
+/// Steps `history` back to the version before its current front, returning the
+/// version rolled back to, or `None` (leaving `history` untouched) if there's
+/// nothing earlier to roll back to.
+fn rollback_in<T: Clone>(history: &mut VecDeque<T>) -> Option<T> {
+    if history.len() <= 1 {
+        return None;
+    }
+
+    history.pop_front();
+    history.front().cloned()
+}
+
+impl<A, R> Clone for Versioned<A, R>
+where
+    A: Asset,
+    R: Clone,
+{
+    fn clone(&self) -> Self {
+        Versioned {
+            inner: self.inner.clone(),
+            history: self.history.clone(),
+            max_versions: self.max_versions,
+        }
+    }
+}
+
+impl<A, R> Reload<A> for Versioned<A, R>
+where
+    A: Asset,
+    R: Clone + Reload<A>,
+    FormatValue<A>: Clone,
+{
+    fn needs_reload(&self) -> bool {
+        self.inner.needs_reload()
+    }
+
+    fn reload(self: Box<Self>) -> Result<FormatValue<A>, BoxedErr> {
+        let Versioned {
+            inner,
+            history,
+            max_versions,
+        } = *self;
+
+        let name = inner.name();
+        let format = inner.format();
+
+        match Box::new(inner).reload() {
+            Ok(mut value) => {
+                value.reload = value.reload.take().map(|next| {
+                    Box::new(Versioned {
+                        inner: next,
+                        history: history.clone(),
+                        max_versions,
+                    }) as Box<Reload<A>>
+                });
+
+                let mut history = history.lock().unwrap();
+                history.push_front(value.clone());
+                history.truncate(max_versions);
+
+                Ok(value)
+            }
+            Err(err) => {
+                let history = history.lock().unwrap();
+
+                match history.front() {
+                    Some(last_good) => {
+                        error!(
+                            "Failed to reload `{}` ({}), keeping last good version: {}",
+                            name, format, err
+                        );
+
+                        Ok(last_good.clone())
+                    }
+                    None => Err(err),
+                }
+            }
+        }
+    }
+
+    fn name(&self) -> String {
+        self.inner.name()
+    }
+
+    fn format(&self) -> &'static str {
+        self.inner.format()
+    }
+}
+
+/// Hashes `bytes` with the same `FnvHasher` construction `HashedFile` compares its
+/// digests with. Pulled out of `HashedFile::hash` so the digest logic can be tested
+/// without a `Source`.
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = FnvHasher::default();
+    hasher.write(bytes);
+
+    hasher.finish()
+}
+
+/// An implementation of `Reload` which detects changes by hashing the loaded bytes
+/// instead of relying on `Source::modified`. Useful for sources whose modification
+/// time is unreliable or unavailable (network filesystems, archives, some virtual
+/// sources) at the cost of re-reading the file on every check.
+pub struct HashedFile<A: Asset, F: Format<A>> {
+    format: F,
+    digest: u64,
+    options: F::Options,
+    path: String,
+    source: Arc<Source>,
+    events: Option<ReloadEvents>,
+}
+
+impl<A: Asset, F: Format<A>> HashedFile<A, F> {
+    /// Creates a new `HashedFile` reload object, hashing the current contents of
+    /// `path` as the baseline to compare future reads against.
+    pub fn new(
+        format: F,
+        options: F::Options,
+        path: String,
+        source: Arc<Source>,
+    ) -> Result<Self, BoxedErr> {
+        let digest = Self::hash(&source, &path)?;
+
+        Ok(HashedFile {
+            format,
+            digest,
+            options,
+            path,
+            source,
+            events: None,
+        })
+    }
+
+    /// Makes this reload object emit an `AssetReloaded` event on `events` whenever it
+    /// successfully reloads, bumping the shared per-asset reload counter.
+    pub fn with_reload_events(mut self, events: ReloadEvents) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    fn hash(source: &Arc<Source>, path: &str) -> Result<u64, BoxedErr> {
+        let bytes = source.load(path)?;
+
+        Ok(hash_bytes(&bytes))
+    }
+}
+
+impl<A, F> Clone for HashedFile<A, F>
+where
+    A: Asset,
+    F: Clone + Format<A>,
+    F::Options: Clone,
+{
+    fn clone(&self) -> Self {
+        HashedFile {
+            format: self.format.clone(),
+            digest: self.digest,
+            options: self.options.clone(),
+            path: self.path.clone(),
+            source: self.source.clone(),
+            events: self.events.clone(),
+        }
+    }
+}
+
+impl<A, F> Reload<A> for HashedFile<A, F>
+where
+    A: Asset,
+    F: Clone + Format<A> + Sync,
+    <F as Format<A>>::Options: Clone + Sync,
+{
+    fn needs_reload(&self) -> bool {
+        Self::hash(&self.source, &self.path)
+            .map(|digest| digest != self.digest)
+            .unwrap_or(false)
+    }
+
+    fn reload(self: Box<Self>) -> Result<FormatValue<A>, BoxedErr> {
+        let this: HashedFile<_, _> = *self;
+        let HashedFile {
+            format,
+            path,
+            source,
+            options,
+            events,
+            ..
+        } = this;
+
+        let name = path.clone();
+        let result = format
+            .import(path.clone(), source.clone(), options.clone(), true)
+            .map(|mut value| {
+                match Self::hash(&source, &path) {
+                    Ok(digest) => {
+                        value.reload = Some(Box::new(HashedFile {
+                            format,
+                            digest,
+                            options,
+                            path,
+                            source,
+                            events: events.clone(),
+                        }));
+                    }
+                    Err(err) => error!(
+                        "Failed to re-hash `{}` after reload, hot-reloading will stop tracking it: {}",
+                        path, err
+                    ),
+                }
+
+                value
+            });
+
+        if result.is_ok() {
+            emit_reload_event(&events, name, F::NAME);
+        }
+
+        result
+    }
+
+    fn name(&self) -> String {
+        self.path.clone()
+    }
+
+    fn format(&self) -> &'static str {
+        F::NAME
+    }
+}
+
+/// An implementation of `Reload` for assets assembled from several files, e.g. a
+/// material referencing textures or a prefab including sub-scenes. Tracks a
+/// modification time per dependency path and re-imports from the full dependency
+/// list if *any* of them have changed, since `Format::import` has no other way to
+/// report the extra paths it read.
+///
+/// `import` stands in for such a hook, which `Format::import` does not have: it's
+/// handed the current dependency list and returns
+/// both the imported value and the (possibly updated) list of paths it read, so
+/// the dependency set stays accurate as the asset's structure changes.
+pub struct MultiFile<A: Asset> {
+    dependencies: Vec<(String, u64)>,
+    format: &'static str,
+    source: Arc<Source>,
+    import: Arc<Fn(&Arc<Source>, &[String]) -> Result<(FormatValue<A>, Vec<String>), BoxedErr> + Send + Sync>,
+    events: Option<ReloadEvents>,
+}
+
+impl<A: Asset> MultiFile<A> {
+    /// Creates a new `MultiFile` reload object, capturing the current modification
+    /// time of every path in `dependencies` as the baseline to compare against.
+    pub fn new<I>(
+        format: &'static str,
+        source: Arc<Source>,
+        dependencies: Vec<String>,
+        import: I,
+    ) -> Self
+    where
+        I: Fn(&Arc<Source>, &[String]) -> Result<(FormatValue<A>, Vec<String>), BoxedErr>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let dependencies = dependencies
+            .into_iter()
+            .map(|path| {
+                let modified = source.modified(&path).unwrap_or(0);
+                (path, modified)
+            })
+            .collect();
+
+        MultiFile {
+            dependencies,
+            format,
+            source,
+            import: Arc::new(import),
+            events: None,
+        }
+    }
+
+    /// Makes this reload object emit an `AssetReloaded` event on `events` whenever it
+    /// successfully reloads, bumping the shared per-asset reload counter.
+    pub fn with_reload_events(mut self, events: ReloadEvents) -> Self {
+        self.events = Some(events);
+        self
+    }
+}
+
+impl<A: Asset> Clone for MultiFile<A> {
+    fn clone(&self) -> Self {
+        MultiFile {
+            dependencies: self.dependencies.clone(),
+            format: self.format,
+            source: self.source.clone(),
+            import: self.import.clone(),
+            events: self.events.clone(),
+        }
+    }
+}
+
+impl<A: Asset> Reload<A> for MultiFile<A> {
+    fn needs_reload(&self) -> bool {
+        self.dependencies.iter().any(|&(ref path, modified)| {
+            modified != 0 && self.source.modified(path).unwrap_or(0) > modified
+        })
+    }
+
+    fn reload(self: Box<Self>) -> Result<FormatValue<A>, BoxedErr> {
+        let this: MultiFile<A> = *self;
+        let MultiFile {
+            dependencies,
+            format,
+            source,
+            import,
+            events,
+        } = this;
+
+        let name = dependencies
+            .first()
+            .map(|&(ref path, _)| path.clone())
+            .unwrap_or_default();
+        let paths: Vec<String> = dependencies.into_iter().map(|(path, _)| path).collect();
+
+        let result = (import)(&source, &paths).map(|(mut value, dependencies)| {
+            let dependencies = dependencies
+                .into_iter()
+                .map(|path| {
+                    let modified = source.modified(&path).unwrap_or(0);
+                    (path, modified)
+                })
+                .collect();
+
+            value.reload = Some(Box::new(MultiFile {
+                dependencies,
+                format,
+                source: source.clone(),
+                import: import.clone(),
+                events: events.clone(),
+            }));
+
+            value
+        });
+
+        if result.is_ok() {
+            emit_reload_event(&events, name, format);
+        }
+
+        result
+    }
+
+    fn name(&self) -> String {
+        self.dependencies
+            .first()
+            .map(|&(ref path, _)| path.clone())
+            .unwrap_or_default()
+    }
+
+    fn format(&self) -> &'static str {
+        self.format
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rollback_with_a_single_version_has_nothing_earlier() {
+        let mut history = VecDeque::new();
+        history.push_front(1);
+
+        assert_eq!(rollback_in(&mut history), None);
+        assert_eq!(history.front(), Some(&1));
+    }
+
+    #[test]
+    fn rollback_steps_back_to_the_previous_version() {
+        let mut history = VecDeque::new();
+        history.push_front(2);
+        history.push_front(3);
+
+        assert_eq!(rollback_in(&mut history), Some(2));
+        assert_eq!(history.front(), Some(&2));
+
+        assert_eq!(rollback_in(&mut history), None);
+    }
+
+    #[test]
+    fn any_settled_waits_out_the_delay() {
+        let delay = Duration::from_millis(20);
+        let mut changed = HashMap::new();
+        changed.insert("a.ron".to_string(), Instant::now());
+
+        assert!(!any_settled(&changed, delay));
+
+        ::std::thread::sleep(delay * 2);
+
+        assert!(any_settled(&changed, delay));
+    }
+
+    #[test]
+    fn take_settled_only_removes_the_settled_path() {
+        let delay = Duration::from_millis(20);
+        let mut changed = HashMap::new();
+        changed.insert("settled.ron".to_string(), Instant::now() - delay * 2);
+        changed.insert("fresh.ron".to_string(), Instant::now());
+
+        assert!(take_settled(&mut changed, "settled.ron", delay));
+        assert!(!changed.contains_key("settled.ron"));
+
+        assert!(!take_settled(&mut changed, "fresh.ron", delay));
+        assert!(changed.contains_key("fresh.ron"));
+
+        assert!(!take_settled(&mut changed, "missing.ron", delay));
+    }
+
+    #[test]
+    fn each_path_debounces_independently() {
+        let delay = Duration::from_millis(20);
+        let mut changed = HashMap::new();
+        changed.insert("old.ron".to_string(), Instant::now() - delay * 2);
+        changed.insert("new.ron".to_string(), Instant::now());
+
+        assert!(any_settled(&changed, delay));
+        assert!(take_settled(&mut changed, "old.ron", delay));
+        assert!(!take_settled(&mut changed, "new.ron", delay));
+    }
+
+    #[test]
+    fn hash_bytes_flips_when_content_changes() {
+        let original = hash_bytes(b"version one");
+        let changed = hash_bytes(b"version two");
+
+        assert_ne!(original, changed);
+        assert_eq!(original, hash_bytes(b"version one"));
+    }
+
+    #[test]
+    fn emit_reload_event_increments_reload_id_per_call() {
+        let channel = Arc::new(Mutex::new(EventChannel::<AssetReloaded>::new()));
+        let mut reader = channel.lock().unwrap().register_reader();
+        let events = Some((channel.clone(), Arc::new(AtomicU64::new(0))));
+
+        emit_reload_event(&events, "a.ron".to_string(), "RON");
+        emit_reload_event(&events, "a.ron".to_string(), "RON");
+
+        let reload_ids: Vec<u64> = channel
+            .lock()
+            .unwrap()
+            .read(&mut reader)
+            .map(|event| event.reload_id)
+            .collect();
+
+        assert_eq!(reload_ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn emit_reload_event_does_nothing_without_a_configured_channel() {
+        emit_reload_event(&None, "a.ron".to_string(), "RON");
+    }
+
+    #[derive(Clone)]
+    struct TestAsset;
+
+    impl Asset for TestAsset {
+        type Data = ();
+    }
+
+    /// An in-memory `Source` test double, mtimes only — `MultiFile` never calls
+    /// `load`, it only compares the mtimes it captured against `modified`.
+    struct TestSource {
+        modified: HashMap<String, u64>,
+    }
+
+    impl Source for TestSource {
+        fn modified(&self, path: &str) -> Result<u64, BoxedErr> {
+            Ok(*self.modified.get(path).unwrap_or(&0))
+        }
+
+        fn load(&self, _path: &str) -> Result<Vec<u8>, BoxedErr> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[test]
+    fn multi_file_reload_does_not_immediately_think_it_needs_reloading_again() {
+        let mut modified = HashMap::new();
+        modified.insert("a.ron".to_string(), 1);
+        modified.insert("b.ron".to_string(), 1);
+        let source: Arc<Source> = Arc::new(TestSource { modified });
+
+        let multi_file = MultiFile::<TestAsset>::new(
+            "TEST",
+            source,
+            vec!["a.ron".to_string(), "b.ron".to_string()],
+            |_source, paths| {
+                Ok((
+                    FormatValue {
+                        data: (),
+                        reload: None,
+                    },
+                    paths.to_vec(),
+                ))
+            },
+        );
+
+        let value = Box::new(multi_file)
+            .reload()
+            .expect("reload of an unchanged MultiFile should succeed");
+        let next = value
+            .reload
+            .expect("MultiFile::reload should thread a next generation forward");
+
+        assert!(!next.needs_reload());
+    }
+}